@@ -0,0 +1,174 @@
+//! Writes a self-contained, cacheable documentation bundle to a
+//! directory: the generated [`OpenApi`] document plus a static docs
+//! viewer, ready to be served from any static file host or CDN.
+//!
+//! # Known limitation: not Swagger UI / ReDoc yet
+//!
+//! [`VIEWER_ASSETS`] currently embeds a bare-bones raw-JSON viewer, not
+//! the real Swagger UI or ReDoc static build — it only fetches
+//! `openapi.json` and renders it as formatted text, with none of the
+//! interactive try-it-out or schema-browsing UI those viewers provide.
+//! This is a placeholder standing in for the actual vendored assets,
+//! not a finished replacement for them: swap [`VIEWER_ASSETS`] for a
+//! real (even trimmed) Swagger UI/ReDoc static build before relying on
+//! this for anything beyond viewing the raw spec.
+//!
+//! Follows rustdoc's `write_shared` approach: viewer assets are
+//! embedded in the binary and written out with a hash of their
+//! contents in the filename (e.g. `viewer.<hash>.js`), so they can be
+//! served with an immutable, long-lived cache header, while
+//! `openapi.json` and `index.html` keep stable names since their
+//! content is specific to each invocation. The hash uses a fixed
+//! FNV-1a implementation rather than `std`'s `DefaultHasher`, whose
+//! algorithm is unspecified and may change across Rust releases,
+//! which would otherwise invalidate every cached filename on a
+//! toolchain upgrade alone.
+
+use std::{fs, io, path::Path};
+
+use crate::openapi::OpenApi;
+
+struct Asset {
+    /// The file stem and extension, e.g. `("viewer", "js")`.
+    name: (&'static str, &'static str),
+    contents: &'static [u8],
+}
+
+const VIEWER_ASSETS: &[Asset] = &[
+    Asset {
+        name: ("viewer", "js"),
+        contents: include_bytes!("../assets/viewer.js"),
+    },
+    Asset {
+        name: ("viewer", "css"),
+        contents: include_bytes!("../assets/viewer.css"),
+    },
+];
+
+/// Writes `api` plus the static docs viewer into `dir`.
+/// ```no_run
+/// # use aide::openapi::OpenApi;
+///
+/// let api = OpenApi::new("3.1.0");
+/// aide::bundle::write_bundle(&api, "./target/docs").unwrap();
+/// ```
+pub fn write_bundle(api: &OpenApi, dir: impl AsRef<Path>) -> io::Result<()> {
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir)?;
+
+    let mut asset_names = Vec::with_capacity(VIEWER_ASSETS.len());
+    for asset in VIEWER_ASSETS {
+        asset_names.push(write_hashed_asset(dir, asset)?);
+    }
+
+    let spec = serde_json::to_vec_pretty(api)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    fs::write(dir.join("openapi.json"), spec)?;
+
+    fs::write(dir.join("index.html"), render_index_html(&asset_names))?;
+
+    Ok(())
+}
+
+/// Writes a single embedded asset under a filename that includes a hash
+/// of its contents, and returns that filename.
+fn write_hashed_asset(dir: &Path, asset: &Asset) -> io::Result<String> {
+    let hash = fnv1a_hash(asset.contents);
+
+    let (stem, ext) = asset.name;
+    let file_name = format!("{stem}.{hash:016x}.{ext}");
+    fs::write(dir.join(&file_name), asset.contents)?;
+    Ok(file_name)
+}
+
+/// A stable 64-bit FNV-1a hash. Unlike `std`'s `DefaultHasher`, this
+/// algorithm is fixed and documented, so filenames derived from it only
+/// change when an asset's contents actually do.
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+fn render_index_html(asset_names: &[String]) -> String {
+    let style_tags = asset_names
+        .iter()
+        .filter(|name| name.ends_with(".css"))
+        .map(|name| format!(r#"<link rel="stylesheet" href="{name}">"#))
+        .collect::<Vec<_>>()
+        .join("\n    ");
+    let script_tags = asset_names
+        .iter()
+        .filter(|name| name.ends_with(".js"))
+        .map(|name| format!(r#"<script src="{name}"></script>"#))
+        .collect::<Vec<_>>()
+        .join("\n    ");
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>API docs</title>
+    {style_tags}
+  </head>
+  <body>
+    <div id="docs"></div>
+    {script_tags}
+  </body>
+</html>
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_bundle_with_hashed_assets_and_matching_references() {
+        let dir = std::env::temp_dir().join(format!(
+            "aide-bundle-test-{}-{}",
+            std::process::id(),
+            fnv1a_hash(file!().as_bytes())
+        ));
+        let _ = fs::remove_dir_all(&dir);
+
+        let api = OpenApi::new("3.1.0");
+        write_bundle(&api, &dir).unwrap();
+
+        let index = fs::read_to_string(dir.join("index.html")).unwrap();
+        let spec = fs::read_to_string(dir.join("openapi.json")).unwrap();
+        assert!(spec.contains("\"openapi\""));
+
+        let mut hashed_asset_count = 0;
+        for asset in VIEWER_ASSETS {
+            let (stem, ext) = asset.name;
+            let expected_name = format!("{stem}.{:016x}.{ext}", fnv1a_hash(asset.contents));
+
+            let written = fs::read(dir.join(&expected_name)).unwrap();
+            assert_eq!(written, asset.contents);
+            assert!(
+                index.contains(&expected_name),
+                "index.html should reference {expected_name}"
+            );
+            hashed_asset_count += 1;
+        }
+        assert_eq!(hashed_asset_count, VIEWER_ASSETS.len());
+
+        // Hashing is deterministic across writes for unchanged content.
+        write_bundle(&api, &dir).unwrap();
+        for asset in VIEWER_ASSETS {
+            let (stem, ext) = asset.name;
+            let expected_name = format!("{stem}.{:016x}.{ext}", fnv1a_hash(asset.contents));
+            assert!(dir.join(&expected_name).exists());
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
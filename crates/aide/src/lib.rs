@@ -0,0 +1,7 @@
+//! `aide` is a code-first OpenAPI document generator for Rust.
+
+pub mod bundle;
+pub mod openapi;
+pub mod transform;
+
+pub(crate) mod util;
@@ -0,0 +1,60 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Contains the schema and examples for a particular media type, keyed
+/// by its identifier (e.g. `application/json`) in the containing
+/// object's `content` map.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, schemars::JsonSchema)]
+pub struct Content {
+    /// The schema defining the content of this media type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub schema: Option<schemars::schema::SchemaObject>,
+    /// An example of the media type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<serde_json::Value>,
+    /// Inline extensions to this object.
+    #[serde(flatten, deserialize_with = "crate::util::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Content {
+    /// Creates a new, empty `Content`.
+    /// ```
+    /// # use aide::openapi::Content;
+    ///
+    /// let content = Content::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the schema for this `Content`.
+    pub fn schema(self, schema: schemars::schema::SchemaObject) -> Self {
+        Self {
+            schema: Some(schema),
+            ..self
+        }
+    }
+
+    /// Sets an example for this `Content`.
+    pub fn example(self, example: impl Into<serde_json::Value>) -> Self {
+        Self {
+            example: Some(example.into()),
+            ..self
+        }
+    }
+
+    /// Sets/adds extensions to this `Content`.
+    pub fn extensions(
+        self,
+        extensions: impl IntoIterator<Item = (impl Into<String>, serde_json::Value)>,
+    ) -> Self {
+        let more_extensions = extensions
+            .into_iter()
+            .map(|(key, value)| (key.into(), value));
+        let mut extensions = self.extensions;
+        extensions.extend(more_extensions);
+        Self { extensions, ..self }
+    }
+}
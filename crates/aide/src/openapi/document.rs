@@ -0,0 +1,75 @@
+use crate::openapi::{Operation, Tag};
+use crate::transform::TagRegistry;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// The root object of an OpenAPI document.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, schemars::JsonSchema)]
+pub struct OpenApi {
+    /// REQUIRED. The semantic version of the OpenAPI Specification used.
+    pub openapi: String,
+    /// A list of tags used by the document, with additional metadata.
+    /// The order of the tags can be used to reflect on their order by
+    /// the parsing tools.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Tag>,
+    /// Inline extensions to this object.
+    #[serde(flatten, deserialize_with = "crate::util::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl OpenApi {
+    /// Creates a new, empty `OpenApi` document for the given OpenAPI
+    /// specification version.
+    pub fn new(openapi: impl Into<String>) -> Self {
+        Self {
+            openapi: openapi.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Scans `operations` for referenced tag names via `registry`,
+    /// deduplicates them, and replaces this document's top-level
+    /// [`tags`](Self::tags) with the aggregated list.
+    /// ```
+    /// # use aide::openapi::{Operation, OpenApi};
+    /// # use aide::transform::TagRegistry;
+    ///
+    /// let mut registry = TagRegistry::new();
+    /// registry.describe("pet", "Pet operations", None);
+    ///
+    /// let operations = vec![Operation::new().tag("pet")];
+    ///
+    /// let mut api = OpenApi::new("3.1.0");
+    /// api.populate_tags(&registry, &operations);
+    /// ```
+    pub fn populate_tags<'a>(
+        &mut self,
+        registry: &TagRegistry,
+        operations: impl IntoIterator<Item = &'a Operation>,
+    ) {
+        self.tags = registry.aggregate(operations);
+    }
+
+    /// Sets/adds extensions to this `OpenApi` document.
+    /// ```
+    /// # use aide::openapi::OpenApi;
+    /// # use aide::transform::{TagGroup, TagGroups};
+    ///
+    /// let mut extensions = indexmap::IndexMap::new();
+    /// TagGroups::new().group(TagGroup::new("Shop")).insert_into(&mut extensions);
+    ///
+    /// let api = OpenApi::new("3.1.0").extensions(extensions);
+    /// ```
+    pub fn extensions(
+        self,
+        extensions: impl IntoIterator<Item = (impl Into<String>, serde_json::Value)>,
+    ) -> Self {
+        let more_extensions = extensions
+            .into_iter()
+            .map(|(key, value)| (key.into(), value));
+        let mut extensions = self.extensions;
+        extensions.extend(more_extensions);
+        Self { extensions, ..self }
+    }
+}
@@ -0,0 +1,57 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Allows referencing an external resource for extended documentation.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, schemars::JsonSchema)]
+pub struct ExternalDocumentation {
+    /// A description of the target documentation.
+    /// CommonMark syntax MAY be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// REQUIRED. The URL for the target documentation.
+    pub url: String,
+    /// Inline extensions to this object.
+    #[serde(flatten, deserialize_with = "crate::util::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl ExternalDocumentation {
+    /// Creates new `ExternalDocumentation` pointing at `url`.
+    /// ```
+    /// # use aide::openapi::ExternalDocumentation;
+    ///
+    /// let docs = ExternalDocumentation::new("https://example.com");
+    /// ```
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets a description for this `ExternalDocumentation`.
+    /// ```
+    /// # use aide::openapi::ExternalDocumentation;
+    ///
+    /// let docs = ExternalDocumentation::new("https://example.com").description("More info");
+    /// ```
+    pub fn description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Sets/adds extensions to this `ExternalDocumentation`.
+    pub fn extensions(
+        self,
+        extensions: impl IntoIterator<Item = (impl Into<String>, serde_json::Value)>,
+    ) -> Self {
+        let more_extensions = extensions
+            .into_iter()
+            .map(|(key, value)| (key.into(), value));
+        let mut extensions = self.extensions;
+        extensions.extend(more_extensions);
+        Self { extensions, ..self }
+    }
+}
@@ -0,0 +1,77 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Describes a single header for a [`crate::openapi::Response`], following
+/// the same shape as a [`crate::openapi::Parameter`] but without a `name`
+/// or `in` field (both are implied by where the header is used).
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, schemars::JsonSchema)]
+pub struct Header {
+    /// A description of the header.
+    /// CommonMark syntax MAY be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Determines whether this header is mandatory.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    /// Specifies that the header is deprecated.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// The schema defining the type used for the header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub schema: Option<schemars::schema::SchemaObject>,
+    /// Inline extensions to this object.
+    #[serde(flatten, deserialize_with = "crate::util::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Header {
+    /// Creates a new, empty `Header`.
+    /// ```
+    /// # use aide::openapi::Header;
+    ///
+    /// let header = Header::new().description("Rate limit remaining");
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a description for this `Header`.
+    pub fn description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Sets whether this `Header` is required.
+    pub fn required(self, required: bool) -> Self {
+        Self { required, ..self }
+    }
+
+    /// Marks this `Header` as deprecated.
+    pub fn deprecated(self, deprecated: bool) -> Self {
+        Self { deprecated, ..self }
+    }
+
+    /// Sets the schema for this `Header`.
+    pub fn schema(self, schema: schemars::schema::SchemaObject) -> Self {
+        Self {
+            schema: Some(schema),
+            ..self
+        }
+    }
+
+    /// Sets/adds extensions to this `Header`.
+    pub fn extensions(
+        self,
+        extensions: impl IntoIterator<Item = (impl Into<String>, serde_json::Value)>,
+    ) -> Self {
+        let more_extensions = extensions
+            .into_iter()
+            .map(|(key, value)| (key.into(), value));
+        let mut extensions = self.extensions;
+        extensions.extend(more_extensions);
+        Self { extensions, ..self }
+    }
+}
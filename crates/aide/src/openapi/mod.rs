@@ -0,0 +1,20 @@
+//! Types that directly map to the [OpenAPI Specification](https://spec.openapis.org/oas/v3.1.0)
+//! and are used to describe the API surface generated by `aide`.
+
+mod content;
+mod document;
+mod external_docs;
+mod header;
+mod operation;
+mod parameter;
+mod response;
+mod tag;
+
+pub use content::*;
+pub use document::*;
+pub use external_docs::*;
+pub use header::*;
+pub use operation::*;
+pub use parameter::*;
+pub use response::*;
+pub use tag::*;
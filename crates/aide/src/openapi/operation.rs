@@ -0,0 +1,121 @@
+use crate::openapi::{ExternalDocumentation, Parameter, Response};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Describes a single API operation on a path.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, schemars::JsonSchema)]
+pub struct Operation {
+    /// A list of tags for API documentation control.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// A short summary of what the operation does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the operation behavior.
+    /// CommonMark syntax MAY be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Additional external documentation for this operation.
+    #[serde(rename = "externalDocs", skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ExternalDocumentation>,
+    /// Unique string used to identify the operation.
+    #[serde(rename = "operationId", skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+    /// A list of parameters applicable for this operation.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<Parameter>,
+    /// REQUIRED. The list of possible responses as they are returned
+    /// from executing this operation, keyed by status code.
+    pub responses: IndexMap<String, Response>,
+    /// Declares this operation to be deprecated.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// Inline extensions to this object.
+    #[serde(flatten, deserialize_with = "crate::util::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Operation {
+    /// Creates a new, empty `Operation`.
+    /// ```
+    /// # use aide::openapi::{Operation, Response};
+    ///
+    /// let op = Operation::new()
+    ///     .summary("List pets")
+    ///     .response("200", Response::new("A list of pets."));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tag to this `Operation`.
+    pub fn tag(self, tag: impl Into<String>) -> Self {
+        let mut tags = self.tags;
+        tags.push(tag.into());
+        Self { tags, ..self }
+    }
+
+    /// Sets a summary for this `Operation`.
+    pub fn summary(self, summary: impl Into<String>) -> Self {
+        Self {
+            summary: Some(summary.into()),
+            ..self
+        }
+    }
+
+    /// Sets a description for this `Operation`.
+    pub fn description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Sets external documentation for this `Operation`.
+    pub fn external_docs(self, external_docs: ExternalDocumentation) -> Self {
+        Self {
+            external_docs: Some(external_docs),
+            ..self
+        }
+    }
+
+    /// Sets the operation id for this `Operation`.
+    pub fn operation_id(self, operation_id: impl Into<String>) -> Self {
+        Self {
+            operation_id: Some(operation_id.into()),
+            ..self
+        }
+    }
+
+    /// Adds a parameter to this `Operation`.
+    pub fn parameter(self, parameter: Parameter) -> Self {
+        let mut parameters = self.parameters;
+        parameters.push(parameter);
+        Self { parameters, ..self }
+    }
+
+    /// Adds a response for a status code to this `Operation`.
+    pub fn response(self, status: impl Into<String>, response: Response) -> Self {
+        let mut responses = self.responses;
+        responses.insert(status.into(), response);
+        Self { responses, ..self }
+    }
+
+    /// Marks this `Operation` as deprecated.
+    pub fn deprecated(self, deprecated: bool) -> Self {
+        Self { deprecated, ..self }
+    }
+
+    /// Sets/adds extensions to this `Operation`.
+    pub fn extensions(
+        self,
+        extensions: impl IntoIterator<Item = (impl Into<String>, serde_json::Value)>,
+    ) -> Self {
+        let more_extensions = extensions
+            .into_iter()
+            .map(|(key, value)| (key.into(), value));
+        let mut extensions = self.extensions;
+        extensions.extend(more_extensions);
+        Self { extensions, ..self }
+    }
+}
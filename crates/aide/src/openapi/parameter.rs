@@ -0,0 +1,101 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// The location of a [`Parameter`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ParameterIn {
+    Query,
+    Header,
+    Path,
+    Cookie,
+}
+
+/// Describes a single operation parameter.
+///
+/// A unique parameter is defined by a combination of
+/// [`name`](Self::name) and [`location`](Self::location).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
+pub struct Parameter {
+    /// REQUIRED. The name of the parameter.
+    pub name: String,
+    /// REQUIRED. The location of the parameter.
+    #[serde(rename = "in")]
+    pub location: ParameterIn,
+    /// A description of the parameter.
+    /// CommonMark syntax MAY be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Determines whether this parameter is mandatory.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    /// Specifies that the parameter is deprecated.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// The schema defining the type used for the parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub schema: Option<schemars::schema::SchemaObject>,
+    /// Inline extensions to this object.
+    #[serde(flatten, deserialize_with = "crate::util::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Parameter {
+    /// Creates a new `Parameter` with the given name and location.
+    /// ```
+    /// # use aide::openapi::{Parameter, ParameterIn};
+    ///
+    /// let param = Parameter::new("id", ParameterIn::Path);
+    /// ```
+    pub fn new(name: impl Into<String>, location: ParameterIn) -> Self {
+        Self {
+            name: name.into(),
+            location,
+            description: None,
+            required: false,
+            deprecated: false,
+            schema: None,
+            extensions: IndexMap::new(),
+        }
+    }
+
+    /// Sets a description for this `Parameter`.
+    pub fn description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Sets whether this `Parameter` is required.
+    pub fn required(self, required: bool) -> Self {
+        Self { required, ..self }
+    }
+
+    /// Marks this `Parameter` as deprecated.
+    pub fn deprecated(self, deprecated: bool) -> Self {
+        Self { deprecated, ..self }
+    }
+
+    /// Sets the schema for this `Parameter`.
+    pub fn schema(self, schema: schemars::schema::SchemaObject) -> Self {
+        Self {
+            schema: Some(schema),
+            ..self
+        }
+    }
+
+    /// Sets/adds extensions to this `Parameter`.
+    pub fn extensions(
+        self,
+        extensions: impl IntoIterator<Item = (impl Into<String>, serde_json::Value)>,
+    ) -> Self {
+        let more_extensions = extensions
+            .into_iter()
+            .map(|(key, value)| (key.into(), value));
+        let mut extensions = self.extensions;
+        extensions.extend(more_extensions);
+        Self { extensions, ..self }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::openapi::{Content, Header};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Describes a single response from an API operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, schemars::JsonSchema)]
+pub struct Response {
+    /// REQUIRED. A description of the response.
+    /// CommonMark syntax MAY be used for rich text representation.
+    pub description: String,
+    /// Maps a header name to its definition.
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub headers: IndexMap<String, Header>,
+    /// A map containing descriptions of potential response payloads,
+    /// keyed by media type.
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub content: IndexMap<String, Content>,
+    /// Inline extensions to this object.
+    #[serde(flatten, deserialize_with = "crate::util::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Response {
+    /// Creates a new `Response` with the given description.
+    /// ```
+    /// # use aide::openapi::{Content, Response};
+    ///
+    /// let response = Response::new("A list of pets.")
+    ///     .content("application/json", Content::new());
+    /// ```
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a header to this `Response`.
+    pub fn header(self, name: impl Into<String>, header: Header) -> Self {
+        let mut headers = self.headers;
+        headers.insert(name.into(), header);
+        Self { headers, ..self }
+    }
+
+    /// Adds content for a media type to this `Response`.
+    pub fn content(self, media_type: impl Into<String>, content: Content) -> Self {
+        let mut content_map = self.content;
+        content_map.insert(media_type.into(), content);
+        Self {
+            content: content_map,
+            ..self
+        }
+    }
+
+    /// Sets/adds extensions to this `Response`.
+    pub fn extensions(
+        self,
+        extensions: impl IntoIterator<Item = (impl Into<String>, serde_json::Value)>,
+    ) -> Self {
+        let more_extensions = extensions
+            .into_iter()
+            .map(|(key, value)| (key.into(), value));
+        let mut extensions = self.extensions;
+        extensions.extend(more_extensions);
+        Self { extensions, ..self }
+    }
+}
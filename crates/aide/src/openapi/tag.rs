@@ -55,7 +55,7 @@ impl Tag {
     /// # use aide::openapi::Tag;
     /// # use aide::openapi::ExternalDocumentation;
     ///
-    /// let docs = ExternalDocumentation { url: "https://example.com".into(), ..Default::default() };
+    /// let docs = ExternalDocumentation::new("https://example.com").description("More info");
     /// let tag = Tag::new("pet").external_docs(docs);
     /// ```
     pub fn external_docs(self, external_docs: ExternalDocumentation) -> Self {
@@ -0,0 +1,7 @@
+//! Transformations applied to a generated [`OpenApi`](crate::openapi::OpenApi)
+//! document, as opposed to the hand-written [`crate::openapi`] types
+//! themselves.
+
+mod tags;
+
+pub use tags::*;
@@ -0,0 +1,199 @@
+use crate::openapi::{ExternalDocumentation, Operation, Tag};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Collects descriptions for tags referenced by name from [`Operation`]s,
+/// so that a document's top-level `tags` array can be generated
+/// automatically instead of being maintained by hand.
+///
+/// Tags that are referenced by an operation but never
+/// [`describe`](Self::describe)d still appear in [`aggregate`](Self::aggregate)'s
+/// output as a bare [`Tag::new`], so that tools relying on a complete
+/// document-level tag list keep working.
+/// ```
+/// # use aide::openapi::Operation;
+/// # use aide::transform::TagRegistry;
+///
+/// let mut registry = TagRegistry::new();
+/// registry.describe("pet", "Pet operations", None);
+///
+/// let operations = vec![Operation::new().tag("pet"), Operation::new().tag("store")];
+/// let tags = registry.aggregate(&operations);
+/// assert_eq!(tags.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TagRegistry {
+    descriptions: IndexMap<String, Tag>,
+}
+
+impl TagRegistry {
+    /// Creates an empty `TagRegistry`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a description (and optional external docs) for a tag
+    /// name. Calling this more than once for the same name overwrites
+    /// the previous description, so reconciling duplicate calls is
+    /// simply "the last one wins".
+    pub fn describe(
+        &mut self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        external_docs: Option<ExternalDocumentation>,
+    ) -> &mut Self {
+        let name = name.into();
+        let mut tag = Tag::new(name.clone()).description(description);
+        if let Some(external_docs) = external_docs {
+            tag = tag.external_docs(external_docs);
+        }
+        self.descriptions.insert(name, tag);
+        self
+    }
+
+    /// Scans the tags referenced by `operations`, deduplicates them, and
+    /// returns the aggregated [`Tag`] list in first-seen order, filling
+    /// in [`describe`](Self::describe)d metadata where available and
+    /// falling back to a bare [`Tag::new`] otherwise.
+    pub fn aggregate<'a>(&self, operations: impl IntoIterator<Item = &'a Operation>) -> Vec<Tag> {
+        let mut seen = IndexMap::<String, Tag>::new();
+        for operation in operations {
+            for name in &operation.tags {
+                if !seen.contains_key(name) {
+                    let tag = self
+                        .descriptions
+                        .get(name)
+                        .cloned()
+                        .unwrap_or_else(|| Tag::new(name.clone()));
+                    seen.insert(name.clone(), tag);
+                }
+            }
+        }
+        seen.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregates_in_first_seen_order() {
+        let registry = TagRegistry::new();
+        let operations = vec![
+            Operation::new().tag("store").tag("pet"),
+            Operation::new().tag("pet").tag("user"),
+        ];
+
+        let tags = registry.aggregate(&operations);
+
+        let names: Vec<&str> = tags.iter().map(|tag| tag.name.as_str()).collect();
+        assert_eq!(names, vec!["store", "pet", "user"]);
+    }
+
+    #[test]
+    fn described_tags_keep_their_description_and_external_docs() {
+        let mut registry = TagRegistry::new();
+        registry.describe(
+            "pet",
+            "Pet operations",
+            Some(ExternalDocumentation::new("https://example.com/pet")),
+        );
+
+        let operations = vec![Operation::new().tag("pet")];
+        let tags = registry.aggregate(&operations);
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name, "pet");
+        assert_eq!(tags[0].description.as_deref(), Some("Pet operations"));
+        assert_eq!(
+            tags[0].external_docs.as_ref().map(|docs| docs.url.as_str()),
+            Some("https://example.com/pet")
+        );
+    }
+
+    #[test]
+    fn undescribed_tags_fall_back_to_bare_tags() {
+        let registry = TagRegistry::new();
+        let operations = vec![Operation::new().tag("pet")];
+
+        let tags = registry.aggregate(&operations);
+
+        assert_eq!(tags, vec![Tag::new("pet")]);
+    }
+
+    #[test]
+    fn later_describe_calls_override_earlier_ones_for_the_same_name() {
+        let mut registry = TagRegistry::new();
+        registry.describe("pet", "First description", None);
+        registry.describe("pet", "Second description", None);
+
+        let operations = vec![Operation::new().tag("pet")];
+        let tags = registry.aggregate(&operations);
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].description.as_deref(), Some("Second description"));
+    }
+}
+
+/// A single entry of the ReDoc `x-tagGroups` extension, grouping related
+/// [`Tag`] names under a navigation category.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
+pub struct TagGroup {
+    /// The name of the group, as shown in the ReDoc sidebar.
+    pub name: String,
+    /// The names of the tags belonging to this group, in display order.
+    pub tags: Vec<String>,
+}
+
+impl TagGroup {
+    /// Creates a new, empty `TagGroup`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tags: Vec::new(),
+        }
+    }
+
+    /// Adds a tag name to this `TagGroup`.
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+}
+
+/// Builds the `x-tagGroups` extension consumed by ReDoc to render
+/// navigation categories in its sidebar.
+/// ```
+/// # use aide::transform::{TagGroup, TagGroups};
+/// # use indexmap::IndexMap;
+///
+/// let mut extensions = IndexMap::new();
+/// TagGroups::new()
+///     .group(TagGroup::new("Shop").tag("pet").tag("store"))
+///     .insert_into(&mut extensions);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TagGroups(Vec<TagGroup>);
+
+impl TagGroups {
+    /// Creates an empty `TagGroups`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a group to this `TagGroups`.
+    pub fn group(mut self, group: TagGroup) -> Self {
+        self.0.push(group);
+        self
+    }
+
+    /// Serializes the groups and inserts them into `extensions` under
+    /// the `x-tagGroups` key used by ReDoc.
+    pub fn insert_into(self, extensions: &mut IndexMap<String, serde_json::Value>) {
+        extensions.insert(
+            "x-tagGroups".to_string(),
+            serde_json::to_value(self.0).expect("TagGroup is always serializable"),
+        );
+    }
+}
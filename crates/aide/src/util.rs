@@ -0,0 +1,17 @@
+//! Internal utilities shared across [`crate::openapi`].
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes an object's catch-all `extensions` map, keeping only
+/// the fields that look like OpenAPI extensions (prefixed with `x-`)
+/// so that unrelated unknown fields aren't silently captured.
+pub(crate) fn deserialize_extensions<'de, D>(
+    deserializer: D,
+) -> Result<IndexMap<String, serde_json::Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let map = IndexMap::<String, serde_json::Value>::deserialize(deserializer)?;
+    Ok(map.into_iter().filter(|(key, _)| key.starts_with("x-")).collect())
+}